@@ -37,11 +37,10 @@ lazy_static! {
     // See:
     // - https://doc.rust-lang.org/nightly/std/sync/struct.Mutex.html
     // - https://os.phil-opp.com/vga-text-mode/#spinlocks
-    pub static ref WRITER: Mutex<Writer> = Mutex::new(Writer {
-       column_position: 0,
-       color_code: ColorCode::new(Color::Yellow, Color::Black),
-       buffer: unsafe { &mut *(0xb8000 as *mut Buffer) }, // 0xb8000 = the location of VGA buffer
-    });
+    pub static ref WRITER: Mutex<Writer> = Mutex::new(Writer::new(
+        unsafe { &mut *(0xb8000 as *mut Buffer) }, // 0xb8000 = the location of VGA buffer
+        ColorCode::new(Color::Yellow, Color::Black),
+    ));
 }
 
 // # COLORS=====================================================================
@@ -97,14 +96,44 @@ pub enum Color {
 // A custom type that contains the full color byte containing background and
 // foreground color Bit 9-11 + 12-14
 // see: https://doc.rust-lang.org/rust-by-example/custom_types/structs.html
-struct ColorCode(u8);
+//
+// Public so callers outside this module can build their own `ColorCode` (e.g.
+// to pass one to `Writer::set_color`), but the inner byte stays private so the
+// bit-packing above remains the only way to produce one.
+pub struct ColorCode(u8);
 
 // impl is used to define methods.
 // see: https://doc.rust-lang.org/rust-by-example/fn/methods.html
 impl ColorCode {
-    fn new(foreground: Color, background: Color) -> ColorCode {
+    pub fn new(foreground: Color, background: Color) -> ColorCode {
         ColorCode((background as u8) << 4 | (foreground as u8))
     }
+
+    // Set bit 7 of the color byte, which the hardware reads as the blink
+    // attribute, when `blink` is true. That bit doubles as the top bit of
+    // the background nibble (the flag that picks a background's "light"
+    // variant, e.g. `DarkGray` over `Black`), so a background outside
+    // `Color::Black..=Color::LightGray` would have that bit clobbered by
+    // blink regardless; to avoid silently corrupting the background, this
+    // clamps it into that 0-7 range before setting the blink bit.
+    //
+    // There's deliberately no symmetric "clear bit 7" behavior for
+    // `blink == false`: once bit 7 is set there's no way to tell, from the
+    // byte alone, whether it means "blinking" or "this background is one of
+    // the light variants" — clearing it unconditionally would silently
+    // downgrade a light background back to its dark counterpart. Callers
+    // that want blink to be optional should keep the "is blinking" decision
+    // separate from the `ColorCode` itself (see `Writer::blink`) and apply
+    // it to the non-blinking byte each time, as
+    // `Writer::effective_color_code` does.
+    pub fn with_blink(self, blink: bool) -> ColorCode {
+        if blink {
+            let ColorCode(byte) = self;
+            ColorCode((byte & 0x7f) | 0x80)
+        } else {
+            self
+        }
+    }
 }
 
 // # TEXT BUFFER ==============================================================
@@ -127,16 +156,247 @@ struct Buffer {
     chars: [[Volatile<ScreenChar>; BUFFER_WIDTH]; BUFFER_HEIGHT]
 }
 
+// # CP437 TRANSLATION ========================================================
+// The VGA text buffer doesn't understand UTF-8 — each byte written to it is
+// looked up in code page 437, the character set burned into the VGA
+// hardware. This table covers the non-ASCII Unicode scalar values CP437 can
+// render: accented Latin letters, block shades, and the single/double
+// box-drawing glyphs. It's sorted by codepoint so `to_cp437` can binary
+// search it instead of growing into a long match arm.
+const CP437_TABLE: &[(char, u8)] = &[
+    ('\u{a1}', 0xad),    // ¡
+    ('\u{a2}', 0x9b),    // ¢
+    ('\u{a3}', 0x9c),    // £
+    ('\u{a5}', 0x9d),    // ¥
+    ('\u{aa}', 0xa6),    // ª
+    ('\u{ab}', 0xae),    // «
+    ('\u{ac}', 0xaa),    // ¬
+    ('\u{ba}', 0xa7),    // º
+    ('\u{bb}', 0xaf),    // »
+    ('\u{bc}', 0xac),    // ¼
+    ('\u{bd}', 0xab),    // ½
+    ('\u{bf}', 0xa8),    // ¿
+    ('\u{c4}', 0x8e),    // Ä
+    ('\u{c5}', 0x8f),    // Å
+    ('\u{c6}', 0x92),    // Æ
+    ('\u{c7}', 0x80),    // Ç
+    ('\u{c9}', 0x90),    // É
+    ('\u{d1}', 0xa5),    // Ñ
+    ('\u{d6}', 0x99),    // Ö
+    ('\u{dc}', 0x9a),    // Ü
+    ('\u{e0}', 0x85),    // à
+    ('\u{e1}', 0xa0),    // á
+    ('\u{e2}', 0x83),    // â
+    ('\u{e4}', 0x84),    // ä
+    ('\u{e5}', 0x86),    // å
+    ('\u{e6}', 0x91),    // æ
+    ('\u{e7}', 0x87),    // ç
+    ('\u{e8}', 0x8a),    // è
+    ('\u{e9}', 0x82),    // é
+    ('\u{ea}', 0x88),    // ê
+    ('\u{eb}', 0x89),    // ë
+    ('\u{ec}', 0x8d),    // ì
+    ('\u{ed}', 0xa1),    // í
+    ('\u{ee}', 0x8c),    // î
+    ('\u{ef}', 0x8b),    // ï
+    ('\u{f1}', 0xa4),    // ñ
+    ('\u{f2}', 0x95),    // ò
+    ('\u{f3}', 0xa2),    // ó
+    ('\u{f4}', 0x93),    // ô
+    ('\u{f6}', 0x94),    // ö
+    ('\u{f9}', 0x97),    // ù
+    ('\u{fa}', 0xa3),    // ú
+    ('\u{fb}', 0x96),    // û
+    ('\u{fc}', 0x81),    // ü
+    ('\u{ff}', 0x98),    // ÿ
+    ('\u{192}', 0x9f),   // ƒ
+    ('\u{20a7}', 0x9e),  // ₧
+    ('\u{2310}', 0xa9),  // ⌐
+    ('\u{2500}', 0xc4),  // ─
+    ('\u{2502}', 0xb3),  // │
+    ('\u{250c}', 0xda),  // ┌
+    ('\u{2510}', 0xbf),  // ┐
+    ('\u{2514}', 0xc0),  // └
+    ('\u{2518}', 0xd9),  // ┘
+    ('\u{251c}', 0xc3),  // ├
+    ('\u{2524}', 0xb4),  // ┤
+    ('\u{252c}', 0xc2),  // ┬
+    ('\u{2534}', 0xc1),  // ┴
+    ('\u{253c}', 0xc5),  // ┼
+    ('\u{2550}', 0xcd),  // ═
+    ('\u{2551}', 0xba),  // ║
+    ('\u{2552}', 0xd5),  // ╒
+    ('\u{2553}', 0xd6),  // ╓
+    ('\u{2554}', 0xc9),  // ╔
+    ('\u{2555}', 0xb8),  // ╕
+    ('\u{2556}', 0xb7),  // ╖
+    ('\u{2557}', 0xbb),  // ╗
+    ('\u{2558}', 0xd4),  // ╘
+    ('\u{2559}', 0xd3),  // ╙
+    ('\u{255a}', 0xc8),  // ╚
+    ('\u{255b}', 0xbe),  // ╛
+    ('\u{255c}', 0xbd),  // ╜
+    ('\u{255d}', 0xbc),  // ╝
+    ('\u{255e}', 0xc6),  // ╞
+    ('\u{255f}', 0xc7),  // ╟
+    ('\u{2560}', 0xcc),  // ╠
+    ('\u{2561}', 0xb5),  // ╡
+    ('\u{2562}', 0xb6),  // ╢
+    ('\u{2563}', 0xb9),  // ╣
+    ('\u{2564}', 0xd1),  // ╤
+    ('\u{2565}', 0xd2),  // ╥
+    ('\u{2566}', 0xcb),  // ╦
+    ('\u{2567}', 0xcf),  // ╧
+    ('\u{2568}', 0xd0),  // ╨
+    ('\u{2569}', 0xca),  // ╩
+    ('\u{256a}', 0xd8),  // ╪
+    ('\u{256b}', 0xd7),  // ╫
+    ('\u{256c}', 0xce),  // ╬
+    ('\u{2580}', 0xdf),  // ▀
+    ('\u{2584}', 0xdc),  // ▄
+    ('\u{2588}', 0xdb),  // █
+    ('\u{258c}', 0xdd),  // ▌
+    ('\u{2590}', 0xde),  // ▐
+    ('\u{2591}', 0xb0),  // ░
+    ('\u{2592}', 0xb1),  // ▒
+    ('\u{2593}', 0xb2),  // ▓
+];
+
+// Translate a Unicode scalar value into the CP437 byte the VGA hardware
+// expects. Printable ASCII passes straight through; anything else is looked
+// up in `CP437_TABLE`, falling back to `0xfe` when there's no CP437
+// equivalent.
+fn to_cp437(c: char) -> u8 {
+    match c {
+        ' '..='~' => c as u8,
+        c => CP437_TABLE
+            .binary_search_by_key(&c, |&(codepoint, _)| codepoint)
+            .map(|i| CP437_TABLE[i].1)
+            .unwrap_or(0xfe),
+    }
+}
+
+// # SCROLLBACK ================================================================
+// Rows that scroll off the top of the visible window used to be discarded by
+// `new_line`. `Scrollback` keeps the last `SCROLLBACK_LINES` of them around
+// in a fixed-size ring buffer (there's no heap in this `no_std` binary, so it
+// can't grow) in oldest-to-newest order, so `Writer::scroll_up` has
+// something to repaint from.
+//
+// `Scrollback` is built by value inside `Writer::new`, which runs on the
+// bootloader's stack the first time `WRITER` is touched, so its `rows` array
+// has to stay well clear of `clippy::large_stack_arrays`' 16 KiB threshold
+// for a single stack allocation. Each row is `BUFFER_WIDTH` (80) `ScreenChar`s
+// at 2 bytes apiece, so this caps out at 64 * 80 * 2 = 10240 bytes.
+const SCROLLBACK_LINES: usize = 64;
+
+struct Scrollback {
+    rows: [[ScreenChar; BUFFER_WIDTH]; SCROLLBACK_LINES],
+    // Index of the oldest stored row.
+    start: usize,
+    // Number of valid rows currently stored (<= SCROLLBACK_LINES).
+    len: usize,
+}
+
+impl Scrollback {
+    fn new(color_code: ColorCode) -> Scrollback {
+        Scrollback {
+            rows: [Writer::blank_row(color_code); SCROLLBACK_LINES],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    // Evict `row` into the ring buffer, overwriting the oldest entry once
+    // it's full.
+    fn push(&mut self, row: [ScreenChar; BUFFER_WIDTH]) {
+        if self.len < SCROLLBACK_LINES {
+            let index = (self.start + self.len) % SCROLLBACK_LINES;
+            self.rows[index] = row;
+            self.len += 1;
+        } else {
+            self.rows[self.start] = row;
+            self.start = (self.start + 1) % SCROLLBACK_LINES;
+        }
+    }
+
+    // `index` counts rows from the oldest stored one (0..self.len).
+    fn row(&self, index: usize) -> [ScreenChar; BUFFER_WIDTH] {
+        self.rows[(self.start + index) % SCROLLBACK_LINES]
+    }
+}
+
 // # WRITER =================================================================
 // Writer is used to abstract away printing into the VGA Buffer.
 pub struct Writer {
     column_position: usize,
+    // The row the caret is actually on, so `update_cursor` has a real
+    // position to program the hardware cursor with instead of a hardcoded
+    // `BUFFER_HEIGHT - 1`. Writing always happens on the bottom row, so this
+    // never moves today, but it gives the cursor code something meaningful
+    // to read.
+    row: usize,
     color_code: ColorCode,
+    // Whether subsequent writes should blink, kept separate from
+    // `color_code` itself — see `ColorCode::with_blink` for why the two
+    // can't be merged into one persisted byte.
+    blink: bool,
     buffer: &'static mut Buffer,
+    // The true, current contents of the 25 visible rows. Kept in lockstep
+    // with `buffer` on every write; `buffer` is only ever allowed to diverge
+    // from it temporarily, while scrolled up through history.
+    live_rows: [[ScreenChar; BUFFER_WIDTH]; BUFFER_HEIGHT],
+    scrollback: Scrollback,
+    // How many lines above the live bottom the visible window currently
+    // shows. 0 means the view is at the live bottom (the normal state).
+    view_offset: usize,
 }
 
 impl Writer {
+    fn blank_row(color_code: ColorCode) -> [ScreenChar; BUFFER_WIDTH] {
+        [ScreenChar {
+            ascii_character: b' ',
+            color_code,
+        }; BUFFER_WIDTH]
+    }
+
+    // Build a `Writer` over `buffer`, seeding `live_rows` from whatever is
+    // already in the buffer (nothing clears the screen on boot, so that's
+    // either leftover BIOS output or, in tests, a caller-constructed buffer).
+    fn new(buffer: &'static mut Buffer, color_code: ColorCode) -> Writer {
+        let mut live_rows = [Writer::blank_row(color_code); BUFFER_HEIGHT];
+        for (live_row, buffer_row) in live_rows.iter_mut().zip(buffer.chars.iter()) {
+            for (live_char, buffer_char) in live_row.iter_mut().zip(buffer_row.iter()) {
+                *live_char = buffer_char.read();
+            }
+        }
+
+        Writer {
+            column_position: 0,
+            row: BUFFER_HEIGHT - 1,
+            color_code,
+            blink: false,
+            buffer,
+            live_rows,
+            scrollback: Scrollback::new(color_code),
+            view_offset: 0,
+        }
+    }
+
+    // The color a character written right now would actually get, folding
+    // in `self.blink` on top of `self.color_code`.
+    fn effective_color_code(&self) -> ColorCode {
+        self.color_code.with_blink(self.blink)
+    }
+
     pub fn write_byte(&mut self, byte: u8) {
+        // Typing (or printing) while scrolled up through history snaps the
+        // view back to the live bottom, the same way a terminal does, so the
+        // new output is immediately visible.
+        if self.view_offset != 0 {
+            self.scroll_to_bottom();
+        }
+
         match byte {
             b'\n' => self.new_line(),
             byte => {
@@ -144,38 +404,52 @@ impl Writer {
                     self.new_line();
                 }
 
-                let row = BUFFER_HEIGHT - 1;
+                let row = self.row;
                 let col = self.column_position;
 
-                let color_code = self.color_code;
-                self.buffer.chars[row][col].write(ScreenChar {
+                let color_code = self.effective_color_code();
+                self.put_char(row, col, ScreenChar {
                     ascii_character: byte,
                     color_code,
                 });
                 self.column_position += 1;
             }
         }
+
+        self.update_cursor();
     }
 
     pub fn write_string(&mut self, s: &str) {
-        for byte in s.bytes() {
-            match byte {
-                // printable ASCII byte or newline
-                0x20...0x7e | b'\n' => self.write_byte(byte),
-                //not part of printable ASCII range
-                _ => self.write_byte(0xfe)
+        // Iterate over chars rather than bytes so multi-byte UTF-8 sequences
+        // get decoded back into a single Unicode scalar value before being
+        // translated, instead of being written out byte by byte as garbage.
+        for c in s.chars() {
+            match c {
+                '\n' => self.write_byte(b'\n'),
+                c => self.write_byte(to_cp437(c)),
             }
         }
     }
 
+    // Write to both the live row cache and, since writes only happen once
+    // the view is back at the bottom, the hardware buffer that mirrors it.
+    fn put_char(&mut self, row: usize, col: usize, screen_char: ScreenChar) {
+        self.live_rows[row][col] = screen_char;
+        self.buffer.chars[row][col].write(screen_char);
+    }
+
     fn new_line(&mut self) {
+        // Row 0 is about to scroll off the top of the visible window; keep
+        // it in the scrollback ring instead of discarding it outright.
+        self.scrollback.push(self.live_rows[0]);
+
         // The printing goes from bottom to top, inserting a new
         // line means shifting all existing printed char up by one line
         // and writing the new line on the bottom of the screen.
         for row in 1..BUFFER_HEIGHT {
             for col in 0..BUFFER_WIDTH {
-                let character = self.buffer.chars[row][col].read();
-                self.buffer.chars[row - 1][col].write(character)
+                let character = self.live_rows[row][col];
+                self.put_char(row - 1, col, character);
             }
         }
         self.clear_row(BUFFER_HEIGHT - 1);
@@ -185,13 +459,245 @@ impl Writer {
     fn clear_row(&mut self, row: usize) {
         let blank = ScreenChar {
             ascii_character: b' ',
-            color_code: self.color_code,
+            color_code: self.effective_color_code(),
         };
         // Replace whole line with whitespace
         for col in 0..BUFFER_WIDTH {
-            self.buffer.chars[row][col].write(blank);
+            self.put_char(row, col, blank);
+        }
+    }
+
+    // Change the color used for subsequent writes. Characters already printed
+    // keep whatever color they were written with, since each `ScreenChar`
+    // carries its own `color_code`.
+    //
+    // Public API; within this binary it's only called from `with_color`.
+    #[allow(dead_code)]
+    pub fn set_color(&mut self, foreground: Color, background: Color) {
+        self.color_code = ColorCode::new(foreground, background);
+    }
+
+    // Toggle the blink attribute on subsequent writes. Kept as its own flag
+    // rather than baked into `color_code` — see `ColorCode::with_blink` for
+    // why disabling blink can't safely be done by clearing a bit in an
+    // already-computed color byte.
+    //
+    // Public API with no caller yet in this binary, same as `Color` above.
+    #[allow(dead_code)]
+    pub fn set_blink(&mut self, blink: bool) {
+        self.blink = blink;
+    }
+
+    // Run `f` with a temporary color, then restore whatever color was active
+    // before the call. Lets callers print a single colored message without
+    // having to remember and restore the previous color themselves.
+    //
+    // Public API; within this binary it's only called from `_print_colored`
+    // (behind `print_colored!`/`println_colored!`), which nothing here uses.
+    #[allow(dead_code)]
+    pub fn with_color<F>(&mut self, foreground: Color, background: Color, f: F)
+    where
+        F: FnOnce(&mut Writer),
+    {
+        let previous_color = self.color_code;
+        self.set_color(foreground, background);
+        f(self);
+        self.color_code = previous_color;
+    }
+
+    // Scroll the visible window `lines` further up into history, clamped to
+    // how much scrollback is actually available. Does not affect
+    // `live_rows`: the live content is still there, just not on screen.
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.view_offset = core::cmp::min(self.view_offset + lines, self.scrollback.len);
+        self.repaint();
+        self.sync_cursor_to_view();
+    }
+
+    // Scroll the visible window `lines` back down toward the live bottom.
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.view_offset = self.view_offset.saturating_sub(lines);
+        self.repaint();
+        self.sync_cursor_to_view();
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        self.view_offset = 0;
+        self.repaint();
+        self.sync_cursor_to_view();
+    }
+
+    // Keep the hardware cursor from appearing to sit inside scrollback text
+    // it has nothing to do with: hide it while the view is scrolled away
+    // from the live bottom, and restore it at the caret once back.
+    fn sync_cursor_to_view(&mut self) {
+        if self.view_offset == 0 {
+            self.show_cursor();
+            self.update_cursor();
+        } else {
+            self.disable_cursor();
+        }
+    }
+
+    // The row that should be on screen at `display_row` (0..BUFFER_HEIGHT)
+    // given the current `view_offset`, stitching the scrollback ring and the
+    // live rows into one continuous timeline.
+    fn visible_row(&self, display_row: usize) -> [ScreenChar; BUFFER_WIDTH] {
+        let scrollback_len = self.scrollback.len;
+        let window_start = scrollback_len.saturating_sub(self.view_offset);
+        let index = window_start + display_row;
+        if index < scrollback_len {
+            self.scrollback.row(index)
+        } else {
+            self.live_rows[index - scrollback_len]
+        }
+    }
+
+    fn repaint(&mut self) {
+        for row in 0..BUFFER_HEIGHT {
+            let screen_row = self.visible_row(row);
+            for (buffer_char, screen_char) in self.buffer.chars[row].iter_mut().zip(screen_row.iter()) {
+                buffer_char.write(*screen_char);
+            }
+        }
+    }
+
+    // Program the CRT Controller so the blinking hardware cursor sits at
+    // `self.row`/`self.column_position`, the linear offset into the 80x25
+    // grid. There's no real CRTC to program under `cfg(test)` (tests run as
+    // an ordinary userspace process, and these are privileged instructions),
+    // so that build has nothing to do here.
+    #[cfg(not(test))]
+    fn update_cursor(&mut self) {
+        let position = self.row * BUFFER_WIDTH + self.column_position;
+        let mut address_port = Port::new(CRTC_ADDRESS_PORT);
+        let mut data_port = Port::new(CRTC_DATA_PORT);
+
+        address_port.write(CURSOR_LOCATION_LOW);
+        data_port.write((position & 0xff) as u8);
+
+        address_port.write(CURSOR_LOCATION_HIGH);
+        data_port.write(((position >> 8) & 0xff) as u8);
+    }
+
+    #[cfg(test)]
+    fn update_cursor(&mut self) {}
+
+    // Show the blinking cursor, shaped as the scanlines from
+    // `start_scanline` (0 = top of the character cell) through
+    // `end_scanline` (15 = bottom).
+    // Public API with no caller yet in this binary, same as `Color` above.
+    #[allow(dead_code)]
+    #[cfg(not(test))]
+    pub fn enable_cursor(&mut self, start_scanline: u8, end_scanline: u8) {
+        let mut address_port = Port::new(CRTC_ADDRESS_PORT);
+        let mut data_port = Port::new(CRTC_DATA_PORT);
+
+        address_port.write(CURSOR_START_REGISTER);
+        let start = (data_port.read() & 0xc0) | start_scanline;
+        data_port.write(start);
+
+        address_port.write(CURSOR_END_REGISTER);
+        let end = (data_port.read() & 0xe0) | end_scanline;
+        data_port.write(end);
+    }
+
+    #[allow(dead_code)]
+    #[cfg(test)]
+    pub fn enable_cursor(&mut self, _start_scanline: u8, _end_scanline: u8) {}
+
+    // Bit 5 of the cursor start register hides the cursor entirely.
+    //
+    // Public API; within this binary it's only called from
+    // `sync_cursor_to_view`, while the view is scrolled up.
+    #[allow(dead_code)]
+    #[cfg(not(test))]
+    pub fn disable_cursor(&mut self) {
+        let mut address_port = Port::new(CRTC_ADDRESS_PORT);
+        let mut data_port = Port::new(CRTC_DATA_PORT);
+
+        address_port.write(CURSOR_START_REGISTER);
+        let disabled = data_port.read() | 0x20;
+        data_port.write(disabled);
+    }
+
+    #[allow(dead_code)]
+    #[cfg(test)]
+    pub fn disable_cursor(&mut self) {}
+
+    // Clear bit 5 of the cursor start register, undoing `disable_cursor`
+    // without touching the scanline shape stored in the rest of that byte.
+    #[cfg(not(test))]
+    fn show_cursor(&mut self) {
+        let mut address_port = Port::new(CRTC_ADDRESS_PORT);
+        let mut data_port = Port::new(CRTC_DATA_PORT);
+
+        address_port.write(CURSOR_START_REGISTER);
+        let shown = data_port.read() & !0x20;
+        data_port.write(shown);
+    }
+
+    #[cfg(test)]
+    fn show_cursor(&mut self) {}
+}
+
+// # HARDWARE CURSOR (CRT CONTROLLER) =========================================
+// The blinking cursor visible in VGA text mode is separate hardware state
+// from the character buffer: it's moved and shaped through two I/O ports on
+// the CRT Controller rather than by writing memory, which needs the
+// `in`/`out` instructions the rest of this module otherwise has no reason to
+// use. None of this exists under `cfg(test)`, where `Writer`'s cursor methods
+// are no-ops, since there's no CRTC to program from an ordinary userspace
+// test process.
+#[cfg(not(test))]
+const CRTC_ADDRESS_PORT: u16 = 0x3d4;
+#[cfg(not(test))]
+const CRTC_DATA_PORT: u16 = 0x3d5;
+#[cfg(not(test))]
+const CURSOR_LOCATION_LOW: u8 = 0x0f;
+#[cfg(not(test))]
+const CURSOR_LOCATION_HIGH: u8 = 0x0e;
+#[cfg(not(test))]
+const CURSOR_START_REGISTER: u8 = 0x0a;
+#[cfg(not(test))]
+const CURSOR_END_REGISTER: u8 = 0x0b;
+
+// A minimal safe-to-construct wrapper around a single x86 I/O port. The
+// `in`/`out` instructions themselves stay `unsafe`, since an arbitrary port
+// can do anything from changing video registers to resetting the machine,
+// but holding a `Port` value is harmless on its own.
+#[cfg(not(test))]
+struct Port(u16);
+
+#[cfg(not(test))]
+impl Port {
+    fn new(address: u16) -> Port {
+        Port(address)
+    }
+
+    fn write(&mut self, value: u8) {
+        unsafe {
+            core::arch::asm!(
+                "out dx, al",
+                in("dx") self.0,
+                in("al") value,
+                options(nomem, nostack, preserves_flags),
+            );
         }
     }
+
+    fn read(&mut self) -> u8 {
+        let value: u8;
+        unsafe {
+            core::arch::asm!(
+                "in al, dx",
+                out("al") value,
+                in("dx") self.0,
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+        value
+    }
 }
 
 // # FORMATTING MACROS SUPPORT ================================================
@@ -242,6 +748,33 @@ pub fn _print(args: fmt::Arguments) {
     WRITER.lock().write_fmt(args).unwrap()
 }
 
+// # COLORED PRINTLN MACROS ===================================================
+// Same as `print!`/`println!` above, but take a foreground/background `Color`
+// pair and restore the writer's previous color once the arguments are
+// printed, via `Writer::with_color`.
+#[macro_export]
+macro_rules! print_colored {
+    ($fg:expr, $bg:expr, $($arg:tt)*) => {
+        $crate::vga_buffers::_print_colored($fg, $bg, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! println_colored {
+    ($fg:expr, $bg:expr) => ($crate::print_colored!($fg, $bg, "\n"));
+    ($fg:expr, $bg:expr, $($arg:tt)*) => (
+        $crate::print_colored!($fg, $bg, "{}\n", format_args!($($arg)*))
+    );
+}
+
+#[doc(hidden)]
+pub fn _print_colored(foreground: Color, background: Color, args: fmt::Arguments) {
+    use core::fmt::Write;
+    WRITER.lock().with_color(foreground, background, |writer| {
+        writer.write_fmt(args).unwrap()
+    });
+}
+
 
 // # TEST  --------============================================================
 #[cfg(test)]
@@ -253,11 +786,8 @@ mod test {
         use std::boxed::Box;
 
         let buffer = construct_buffer();
-        Writer {
-            column_position: 0,
-            color_code: ColorCode::new(Color::Blue, Color::Magenta),
-            buffer: Box::leak(Box::new(buffer)),
-        }
+        let color_code = ColorCode::new(Color::Blue, Color::Magenta);
+        Writer::new(Box::leak(Box::new(buffer)), color_code)
     }
 
     fn construct_buffer() -> Buffer {
@@ -275,6 +805,128 @@ mod test {
         }
     }
 
+    #[test]
+    fn with_blink_sets_bit_and_clamps_light_background() {
+        let plain = ColorCode::new(Color::White, Color::Blue);
+        let ColorCode(plain_byte) = plain;
+        let ColorCode(blinking_byte) = plain.with_blink(true);
+        assert_eq!(blinking_byte, plain_byte | 0x80);
+
+        // A "light" background (bit 3 of the background nibble set) would
+        // collide with the blink bit, so it gets clamped into 0-7 first.
+        let light_bg = ColorCode::new(Color::White, Color::LightBlue);
+        let ColorCode(light_bg_byte) = light_bg.with_blink(true);
+        assert_eq!(light_bg_byte, ColorCode::new(Color::White, Color::Blue).with_blink(true).0);
+    }
+
+    #[test]
+    fn with_blink_false_never_touches_the_background() {
+        // Bit 7 is ambiguous on its own: it's either "blinking" or the
+        // background's light flag. `with_blink(false)` must leave it alone
+        // rather than guessing, or a light background silently downgrades to
+        // its dark counterpart (e.g. LightBlue -> Blue).
+        let light_bg = ColorCode::new(Color::White, Color::LightBlue);
+        assert_eq!(light_bg.with_blink(false), light_bg);
+
+        let blinking = ColorCode::new(Color::White, Color::Blue).with_blink(true);
+        assert_eq!(blinking.with_blink(false), blinking);
+    }
+
+    #[test]
+    fn set_blink_toggles_flag_without_touching_color_code() {
+        let mut writer = construct_writer();
+        let base_color = writer.color_code;
+
+        writer.set_blink(true);
+        assert!(writer.blink);
+        assert_eq!(writer.color_code, base_color);
+        assert_eq!(writer.effective_color_code(), base_color.with_blink(true));
+
+        writer.set_blink(false);
+        assert!(!writer.blink);
+        assert_eq!(writer.color_code, base_color);
+        assert_eq!(writer.effective_color_code(), base_color);
+    }
+
+    #[test]
+    fn set_blink_does_not_corrupt_light_background() {
+        let mut writer = construct_writer();
+        writer.set_color(Color::White, Color::LightBlue);
+
+        writer.set_blink(true);
+        writer.set_blink(false);
+
+        // Disabling blink again must not have downgraded the light
+        // background, since it was never blinking to begin with.
+        assert_eq!(
+            writer.effective_color_code(),
+            ColorCode::new(Color::White, Color::LightBlue)
+        );
+    }
+
+    #[test]
+    fn scroll_up_and_down_reveal_and_restore_history() {
+        use core::fmt::Write;
+
+        let mut writer = construct_writer();
+        // Print more lines than fit on screen so some of them scroll into
+        // the scrollback ring.
+        for line in 0..(BUFFER_HEIGHT + 5) {
+            writeln!(&mut writer, "{}", line).unwrap();
+        }
+
+        let live_top_row = writer.buffer.chars[0][0].read();
+
+        writer.scroll_up(3);
+        assert_eq!(writer.view_offset, 3);
+        let scrolled_top_row = writer.buffer.chars[0][0].read();
+        assert_ne!(scrolled_top_row, live_top_row);
+
+        writer.scroll_down(3);
+        assert_eq!(writer.view_offset, 0);
+        let restored_top_row = writer.buffer.chars[0][0].read();
+        assert_eq!(restored_top_row, live_top_row);
+    }
+
+    #[test]
+    fn write_while_scrolled_snaps_view_to_bottom() {
+        use core::fmt::Write;
+
+        let mut writer = construct_writer();
+        for line in 0..(BUFFER_HEIGHT + 5) {
+            writeln!(&mut writer, "{}", line).unwrap();
+        }
+
+        writer.scroll_up(3);
+        assert_eq!(writer.view_offset, 3);
+
+        writer.write_byte(b'Z');
+        assert_eq!(writer.view_offset, 0);
+    }
+
+    #[test]
+    fn to_cp437_translates_known_glyphs() {
+        assert_eq!(to_cp437(' '), b' ');
+        assert_eq!(to_cp437('~'), b'~');
+        assert_eq!(to_cp437('\u{2591}'), 0xb0); // ░
+        assert_eq!(to_cp437('\u{2592}'), 0xb1); // ▒
+        assert_eq!(to_cp437('\u{2593}'), 0xb2); // ▓
+        assert_eq!(to_cp437('\u{e9}'), 0x82); // é
+        assert_eq!(to_cp437('\u{fc}'), 0x81); // ü
+        assert_eq!(to_cp437('\u{f1}'), 0xa4); // ñ
+        assert_eq!(to_cp437('\u{2588}'), 0xdb); // █
+        assert_eq!(to_cp437('\u{1f600}'), 0xfe); // unrepresentable
+    }
+
+    #[test]
+    fn write_string_translates_non_ascii() {
+        let mut writer = construct_writer();
+        writer.write_string("\u{2591}");
+
+        let written = writer.buffer.chars[BUFFER_HEIGHT - 1][0].read();
+        assert_eq!(written.ascii_character, 0xb0);
+    }
+
     #[test]
     fn write_byte() {
         let mut writer = construct_writer();
@@ -326,6 +978,21 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn with_color_restores_previous_color() {
+        let mut writer = construct_writer();
+        let original_color = writer.color_code;
+
+        writer.with_color(Color::Red, Color::White, |w| {
+            assert_eq!(w.color_code, ColorCode::new(Color::Red, Color::White));
+            w.write_byte(b'X');
+        });
+
+        assert_eq!(writer.color_code, original_color);
+        let written = writer.buffer.chars[BUFFER_HEIGHT - 1][0].read();
+        assert_eq!(written.color_code, ColorCode::new(Color::Red, Color::White));
+    }
 }
 
 #[test]